@@ -38,10 +38,12 @@ fn main() {
             Character::Id, Character::Character, Character::FontSize, Character::JsonField,
         ])
         .json(serde_json::to_value(item).unwrap())
+        .returning(vec![Character::Id])
         .build(PostgresQueryBuilder);
 
-    let result = client.execute(sql.as_str(), &Values::from(values).as_params());
-    println!("Insert into character: {:?}\n", result);
+    let row = client.query_one(sql.as_str(), &Values::from(values).as_params()).unwrap();
+    let id: i64 = row.get("id");
+    println!("Insert into character, returning id: {}\n", id);
 
     // Read
 
@@ -50,19 +52,15 @@ fn main() {
             Character::Id, Character::Character, Character::FontSize, Character::JsonField,
         ])
         .from(Character::Table)
-        .order_by(Character::Id, Order::Desc)
-        .limit(1)
+        .and_where(Expr::col(Character::Id).eq(id))
         .build(PostgresQueryBuilder);
 
     let rows = client.query(sql.as_str(), &Values::from(values).as_params()).unwrap();
     println!("Select one from character:");
-    let mut id = None;
     for row in rows.into_iter() {
         let item = CharacterStruct::from(row);
         println!("{:?}", item);
-        id = Some(item.id);
     }
-    let id = id.unwrap();
     println!();
 
     // Update
@@ -0,0 +1,85 @@
+use super::*;
+
+impl QueryBuilder for MysqlQueryBuilder {
+    fn prepare_insert_statement(&self, insert: &InsertStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        write!(sql, "INSERT INTO ").unwrap();
+
+        if let Some(table) = &insert.table {
+            self.prepare_table_ref(table, sql, collector);
+        }
+
+        if insert.default_values {
+            write!(sql, " () VALUES ()").unwrap();
+        } else {
+            write!(sql, " (").unwrap();
+            insert.columns.iter().fold(true, |first, col| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                col.prepare(sql, '`');
+                false
+            });
+            write!(sql, ")").unwrap();
+
+            match &insert.source {
+                InsertValueSource::Values(rows) => {
+                    write!(sql, " VALUES ").unwrap();
+                    rows.iter().fold(true, |first, row| {
+                        if !first {
+                            write!(sql, ", ").unwrap()
+                        }
+                        write!(sql, "(").unwrap();
+                        row.iter().fold(true, |first, col| {
+                            if !first {
+                                write!(sql, ", ").unwrap()
+                            }
+                            match col {
+                                InsertValue::Value(value) => self.prepare_value(value, sql, collector),
+                                InsertValue::Default => write!(sql, "DEFAULT").unwrap(),
+                            }
+                            false
+                        });
+                        write!(sql, ")").unwrap();
+                        false
+                    });
+                }
+                InsertValueSource::Select(select) => {
+                    write!(sql, " ").unwrap();
+                    self.prepare_select_statement(select, sql, collector);
+                }
+            }
+        }
+
+        if let Some(on_conflict) = &insert.on_conflict {
+            mysql_prepare_on_conflict(on_conflict, sql);
+        }
+    }
+}
+
+/// Emit the `ON DUPLICATE KEY UPDATE ...` tail of an upsert. Unlike Postgres/SQLite's
+/// `ON CONFLICT`, MySQL has no `DO NOTHING` equivalent and no named conflict target list;
+/// a bare `DoNothing` upsert degrades to a no-op `ON DUPLICATE KEY UPDATE id = id` on the
+/// first conflict target column, keeping the row untouched.
+pub(crate) fn mysql_prepare_on_conflict(on_conflict: &OnConflict, sql: &mut SqlWriter) {
+    write!(sql, " ON DUPLICATE KEY UPDATE ").unwrap();
+    match &on_conflict.action {
+        OnConflictAction::DoNothing => {
+            let col = on_conflict.targets.first().expect("on conflict must target at least one column");
+            col.prepare(sql, '`');
+            write!(sql, " = ").unwrap();
+            col.prepare(sql, '`');
+        }
+        OnConflictAction::Update(columns) => {
+            columns.iter().fold(true, |first, col| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                col.prepare(sql, '`');
+                write!(sql, " = VALUES(").unwrap();
+                col.prepare(sql, '`');
+                write!(sql, ")").unwrap();
+                false
+            });
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+impl QueryBuilder for PostgresQueryBuilder {
+    fn prepare_insert_statement(&self, insert: &InsertStatement, sql: &mut SqlWriter, collector: &mut dyn FnMut(Value)) {
+        write!(sql, "INSERT INTO ").unwrap();
+
+        if let Some(table) = &insert.table {
+            self.prepare_table_ref(table, sql, collector);
+        }
+
+        if insert.default_values {
+            write!(sql, " DEFAULT VALUES").unwrap();
+        } else {
+            write!(sql, " (").unwrap();
+            insert.columns.iter().fold(true, |first, col| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                col.prepare(sql, '"');
+                false
+            });
+            write!(sql, ")").unwrap();
+
+            match &insert.source {
+                InsertValueSource::Values(rows) => {
+                    write!(sql, " VALUES ").unwrap();
+                    rows.iter().fold(true, |first, row| {
+                        if !first {
+                            write!(sql, ", ").unwrap()
+                        }
+                        write!(sql, "(").unwrap();
+                        row.iter().fold(true, |first, col| {
+                            if !first {
+                                write!(sql, ", ").unwrap()
+                            }
+                            match col {
+                                InsertValue::Value(value) => self.prepare_value(value, sql, collector),
+                                InsertValue::Default => write!(sql, "DEFAULT").unwrap(),
+                            }
+                            false
+                        });
+                        write!(sql, ")").unwrap();
+                        false
+                    });
+                }
+                InsertValueSource::Select(select) => {
+                    write!(sql, " ").unwrap();
+                    self.prepare_select_statement(select, sql, collector);
+                }
+            }
+        }
+
+        if let Some(on_conflict) = &insert.on_conflict {
+            prepare_on_conflict_excluded(on_conflict, sql, '"');
+        }
+
+        prepare_returning(&insert.returning, sql, '"');
+    }
+}
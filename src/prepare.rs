@@ -0,0 +1,52 @@
+use std::rc::Rc;
+use crate::*;
+
+/// Emit the `ON CONFLICT (...) DO UPDATE SET ... / DO NOTHING` tail of an upsert
+/// for backends that reference the proposed row as `excluded.col` (Postgres, SQLite).
+pub(crate) fn prepare_on_conflict_excluded(on_conflict: &OnConflict, sql: &mut SqlWriter, q: char) {
+    write!(sql, " ON CONFLICT (").unwrap();
+    on_conflict.targets.iter().fold(true, |first, col| {
+        if !first {
+            write!(sql, ", ").unwrap()
+        }
+        col.prepare(sql, q);
+        false
+    });
+    write!(sql, ")").unwrap();
+
+    match &on_conflict.action {
+        OnConflictAction::DoNothing => {
+            write!(sql, " DO NOTHING").unwrap();
+        }
+        OnConflictAction::Update(columns) => {
+            write!(sql, " DO UPDATE SET ").unwrap();
+            columns.iter().fold(true, |first, col| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                col.prepare(sql, q);
+                write!(sql, " = ").unwrap();
+                Alias::new("excluded").prepare(sql, q);
+                write!(sql, ".").unwrap();
+                col.prepare(sql, q);
+                false
+            });
+        }
+    }
+}
+
+/// Emit the `RETURNING col, ...` clause for backends that support it (Postgres, SQLite);
+/// does nothing if no columns were requested.
+pub(crate) fn prepare_returning(returning: &[Rc<dyn Iden>], sql: &mut SqlWriter, q: char) {
+    if returning.is_empty() {
+        return;
+    }
+    write!(sql, " RETURNING ").unwrap();
+    returning.iter().fold(true, |first, col| {
+        if !first {
+            write!(sql, ", ").unwrap()
+        }
+        col.prepare(sql, q);
+        false
+    });
+}
@@ -1,6 +1,8 @@
 use std::rc::Rc;
+use std::fmt::Write;
 use serde_json::Value as JsonValue;
-use crate::{backend::QueryBuilder, types::*, value::*, prepare::*};
+use crate::{backend::{QueryBuilder, PostgresQueryBuilder}, types::*, value::*, prepare::*};
+use super::SelectStatement;
 
 /// Insert any new rows into an existing table
 /// 
@@ -42,7 +44,10 @@ use crate::{backend::QueryBuilder, types::*, value::*, prepare::*};
 pub struct InsertStatement {
     pub(crate) table: Option<Box<TableRef>>,
     pub(crate) columns: Vec<Rc<dyn Iden>>,
-    pub(crate) values: Vec<Vec<Value>>,
+    pub(crate) source: InsertValueSource,
+    pub(crate) on_conflict: Option<OnConflict>,
+    pub(crate) returning: Vec<Rc<dyn Iden>>,
+    pub(crate) default_values: bool,
 }
 
 impl Default for InsertStatement {
@@ -57,7 +62,10 @@ impl InsertStatement {
         Self {
             table: None,
             columns: Vec::new(),
-            values: Vec::new(),
+            source: InsertValueSource::Values(Vec::new()),
+            on_conflict: None,
+            returning: Vec::new(),
+            default_values: false,
         }
     }
 
@@ -144,7 +152,15 @@ impl InsertStatement {
         if self.columns.len() != values.len() {
             return Err(format!("columns and values length mismatch: {} != {}", self.columns.len(), values.len()));
         }
-        self.values.push(values);
+        match &mut self.source {
+            InsertValueSource::Values(rows) => {
+                rows.push(values.into_iter().map(InsertValue::from).collect());
+            }
+            InsertValueSource::Select(_) => {
+                return Err("cannot add values to an INSERT ... SELECT statement".to_owned());
+            }
+        }
+        self.default_values = false;
         Ok(self)
     }
 
@@ -154,12 +170,16 @@ impl InsertStatement {
     }
 
     /// Specify a row of values to be inserted, taking input of json values.
-    /// 
+    ///
+    /// A column present in [`InsertStatement::columns`] but missing from `object`
+    /// is written as the bare SQL keyword `DEFAULT`, falling back to the column's
+    /// database default, rather than binding `Value::Null`.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use sea_query::{*, tests_cfg::*};
-    /// 
+    ///
     /// let query = Query::insert()
     ///     .into_table(Glyph::Table)
     ///     .columns(vec![
@@ -206,22 +226,237 @@ impl InsertStatement {
         for col in self.columns.iter() {
             values.push(
                 match object.get(col.to_string()) {
-                    Some(value) => json_value_to_sea_value(value),
-                    None => Value::Null,
+                    Some(value) => InsertValue::Value(json_value_to_sea_value(value)),
+                    None => InsertValue::Default,
                 }
             );
         }
-        self.values.push(values);
+        match &mut self.source {
+            InsertValueSource::Values(rows) => rows.push(values),
+            InsertValueSource::Select(_) => panic!("cannot add values to an INSERT ... SELECT statement"),
+        }
+        self.default_values = false;
         self
     }
 
-    /// Build corresponding SQL statement for certain database backend and collect query parameters
-    /// 
+    /// Insert the result set of a `SELECT` statement instead of literal value rows,
+    /// i.e. `INSERT INTO tbl (cols) SELECT ...`.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```
     /// use sea_query::{*, tests_cfg::*};
-    /// 
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![
+    ///         Glyph::Aspect,
+    ///         Glyph::Image,
+    ///     ])
+    ///     .select_from(
+    ///         Query::select()
+    ///             .columns(vec![
+    ///                 Glyph::Aspect,
+    ///                 Glyph::Image,
+    ///             ])
+    ///             .from(Glyph::Table)
+    ///             .to_owned()
+    ///     )
+    ///     .unwrap()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("aspect", "image") SELECT "aspect", "image" FROM "glyph""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`aspect`, `image`) SELECT `aspect`, `image` FROM `glyph`"#
+    /// );
+    /// ```
+    pub fn select_from(&mut self, select: SelectStatement) -> Result<&mut Self, String> {
+        if let InsertValueSource::Values(rows) = &self.source {
+            if !rows.is_empty() {
+                return Err("cannot insert from select after values have already been added".to_owned());
+            }
+        }
+        self.source = InsertValueSource::Select(Box::new(select));
+        self.default_values = false;
+        Ok(self)
+    }
+
+    /// Insert a single row of database-assigned default values, i.e.
+    /// `INSERT INTO tbl DEFAULT VALUES` (Postgres, SQLite) or
+    /// `INSERT INTO tbl () VALUES ()` (MySQL, which has no `DEFAULT VALUES` form).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .default_values()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" DEFAULT VALUES"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO `glyph` DEFAULT VALUES"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` () VALUES ()"#
+    /// );
+    /// ```
+    pub fn default_values(&mut self) -> &mut Self {
+        self.columns = Vec::new();
+        self.source = InsertValueSource::Values(Vec::new());
+        self.default_values = true;
+        self
+    }
+
+    /// Specify the `ON CONFLICT` clause of the insert, to express an upsert.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![
+    ///         Glyph::Id,
+    ///         Glyph::Aspect,
+    ///         Glyph::Image,
+    ///     ])
+    ///     .values_panic(vec![
+    ///         3.into(),
+    ///         3.1415.into(),
+    ///         "041".into(),
+    ///     ])
+    ///     .on_conflict(
+    ///         OnConflict::columns(vec![Glyph::Id])
+    ///             .unwrap()
+    ///             .update_columns(vec![Glyph::Aspect, Glyph::Image])
+    ///             .unwrap()
+    ///             .to_owned()
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("id", "aspect", "image") VALUES (3, 3.1415, '041') ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect", "image" = "excluded"."image""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `aspect`, `image`) VALUES (3, 3.1415, '041') ON CONFLICT (`id`) DO UPDATE SET `aspect` = `excluded`.`aspect`, `image` = `excluded`.`image`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`id`, `aspect`, `image`) VALUES (3, 3.1415, '041') ON DUPLICATE KEY UPDATE `aspect` = VALUES(`aspect`), `image` = VALUES(`image`)"#
+    /// );
+    /// ```
+    pub fn on_conflict(&mut self, on_conflict: OnConflict) -> &mut Self {
+        self.on_conflict = Some(on_conflict);
+        self
+    }
+
+    /// Specify the columns to return after the insert completes, e.g. a
+    /// generated id, so the caller can read back the row in the same
+    /// round trip instead of issuing a separate `SELECT`.
+    ///
+    /// Only [`PostgresQueryBuilder`] and [`SqliteQueryBuilder`] support
+    /// `RETURNING`; [`MysqlQueryBuilder`] has no such clause and omits it.
+    /// `UpdateStatement` and `DeleteStatement` do not yet support `RETURNING`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![
+    ///         Glyph::Aspect,
+    ///         Glyph::Image,
+    ///     ])
+    ///     .values_panic(vec![
+    ///         5.15.into(),
+    ///         "12A".into(),
+    ///     ])
+    ///     .returning(vec![Glyph::Id])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("aspect", "image") VALUES (5.15, '12A') RETURNING "id""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`aspect`, `image`) VALUES (5.15, '12A') RETURNING `id`"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"INSERT INTO `glyph` (`aspect`, `image`) VALUES (5.15, '12A')"#
+    /// );
+    /// ```
+    pub fn returning<C: 'static>(&mut self, columns: Vec<C>) -> &mut Self
+        where C: Iden {
+        self.returning_dyn(columns.into_iter().map(|c| Rc::new(c) as Rc<dyn Iden>).collect())
+    }
+
+    /// Specify the columns to return after the insert, variation of [`InsertStatement::returning`].
+    pub fn returning_dyn(&mut self, columns: Vec<Rc<dyn Iden>>) -> &mut Self {
+        self.returning = columns;
+        self
+    }
+
+    /// Look up the ordinal (0-based) position of `column` within the
+    /// [`InsertStatement::returning`] list, for reading the result row back by
+    /// index (e.g. `row.try_get_by(idx)`) instead of by column name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![Glyph::Aspect])
+    ///     .values_panic(vec![5.15.into()])
+    ///     .returning(vec![Glyph::Image, Glyph::Id])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(query.returning_index_of(Glyph::Id), Some(1));
+    /// assert_eq!(query.returning_index_of(Glyph::Aspect), None);
+    /// ```
+    pub fn returning_index_of<C: 'static>(&self, column: C) -> Option<usize>
+        where C: Iden {
+        let name = column.to_string();
+        self.returning.iter().position(|col| col.to_string() == name)
+    }
+
+    /// Build corresponding SQL statement for certain database backend and collect query parameters.
+    ///
+    /// `collector` is invoked once per bound [`Value`], in the order the SQL text
+    /// references them. A value is never collected twice for the same row:
+    /// [`OnConflict::update_columns`] emits a reference to the proposed row
+    /// (`excluded.col` / `VALUES(col)`) rather than re-binding it, so an upsert's
+    /// `INSERT` row and its `DO UPDATE` clause can't disagree on a parameter.
+    ///
+    /// This emits one `?` placeholder per bound value regardless of backend; use
+    /// [`InsertStatement::build_collect_numbered`] for reusable, numbered
+    /// placeholders (`$1, $2, ...`) on Postgres.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
     /// let query = Query::insert()
     ///     .into_table(Glyph::Table)
     ///     .columns(vec![
@@ -233,15 +468,15 @@ impl InsertStatement {
     ///         "041080".into(),
     ///     ])
     ///     .to_owned();
-    /// 
+    ///
     /// assert_eq!(
     ///     query.to_string(MysqlQueryBuilder),
     ///     r#"INSERT INTO `glyph` (`aspect`, `image`) VALUES (3.1415, '041080')"#
     /// );
-    /// 
+    ///
     /// let mut params = Vec::new();
     /// let mut collector = |v| params.push(v);
-    /// 
+    ///
     /// assert_eq!(
     ///     query.build_collect(MysqlQueryBuilder, &mut collector),
     ///     r#"INSERT INTO `glyph` (`aspect`, `image`) VALUES (?, ?)"#
@@ -260,6 +495,106 @@ impl InsertStatement {
         sql.result()
     }
 
+    /// Build corresponding SQL statement using numbered, reusable placeholders
+    /// (`$1, $2, ...`) instead of one `?` per bound value. An equal [`Value`]
+    /// bound more than once collapses onto a single placeholder number and is
+    /// collected only once, so the same parameter can be referenced twice in
+    /// the emitted SQL without being pushed twice.
+    ///
+    /// Only [`PostgresQueryBuilder`] uses numbered, reusable placeholders;
+    /// MySQL and SQLite only accept sequential, non-reusable `?` and have no
+    /// way to express "the same bound parameter again", so this method is
+    /// restricted to Postgres rather than silently mis-binding on the other
+    /// two backends. Use [`InsertStatement::build_collect`] for those.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the statement was built with [`InsertStatement::select_from`].
+    /// The rewrite below only recognizes a `?` as a placeholder outside a
+    /// double-quoted identifier; it has no way to tell a literal `?` inside a
+    /// single-quoted string or other raw SQL text in the subquery from an
+    /// actual placeholder, so a `SELECT` source isn't safe to rewrite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns(vec![
+    ///         Glyph::Aspect,
+    ///         Glyph::Image,
+    ///     ])
+    ///     .values_panic(vec![
+    ///         5.15.into(),
+    ///         5.15.into(),
+    ///     ])
+    ///     .to_owned();
+    ///
+    /// let mut params = Vec::new();
+    /// let mut collector = |v| params.push(v);
+    ///
+    /// assert_eq!(
+    ///     query.build_collect_numbered(PostgresQueryBuilder, &mut collector),
+    ///     r#"INSERT INTO "glyph" ("aspect", "image") VALUES ($1, $1)"#
+    /// );
+    /// assert_eq!(params, vec![Value::Double(5.15)]);
+    /// ```
+    pub fn build_collect_numbered(&self, query_builder: PostgresQueryBuilder, collector: &mut dyn FnMut(Value)) -> String {
+        if let InsertValueSource::Select(_) = &self.source {
+            panic!("build_collect_numbered does not support INSERT ... SELECT: the subquery may contain raw SQL text with a literal `?` that the quote-aware scan can't distinguish from a real placeholder");
+        }
+        let mut raw_values = Vec::new();
+        let sql = self.build_collect(query_builder, &mut |v| raw_values.push(v));
+
+        let mut numbered_values: Vec<Value> = Vec::new();
+        let mut placeholder_numbers = Vec::with_capacity(raw_values.len());
+        for value in raw_values {
+            let number = match numbered_values.iter().position(|v| v == &value) {
+                Some(index) => index + 1,
+                None => {
+                    numbered_values.push(value);
+                    numbered_values.len()
+                }
+            };
+            placeholder_numbers.push(number);
+        }
+        for value in numbered_values {
+            collector(value);
+        }
+
+        // Postgres identifiers are double-quoted, so only a `?` outside a quoted
+        // span is a placeholder; one inside e.g. `"weird?col"` is left untouched.
+        let mut in_quotes = false;
+        let mut numbers = placeholder_numbers.into_iter();
+        let mut result = String::with_capacity(sql.len());
+        for ch in sql.chars() {
+            match ch {
+                '"' => {
+                    in_quotes = !in_quotes;
+                    result.push(ch);
+                }
+                '?' if !in_quotes => {
+                    let number = numbers.next().expect("placeholder/value count mismatch");
+                    write!(result, "${}", number).unwrap();
+                }
+                _ => result.push(ch),
+            }
+        }
+        result
+    }
+
+    /// Build corresponding SQL statement using numbered, reusable placeholders and
+    /// collect query parameters into a vector, variation of
+    /// [`InsertStatement::build_collect_numbered`].
+    pub fn build_numbered(&self, query_builder: PostgresQueryBuilder) -> (String, Vec<Value>) {
+        let mut params = Vec::new();
+        let mut collector = |v| params.push(v);
+        let sql = self.build_collect_numbered(query_builder, &mut collector);
+        (sql, params)
+    }
+
     /// Build corresponding SQL statement for certain database backend and collect query parameters
     pub fn build_collect_any(&self, query_builder: &dyn QueryBuilder, collector: &mut dyn FnMut(Value)) -> String {
         let mut sql = SqlWriter::new();
@@ -341,4 +676,120 @@ impl InsertStatement {
         let (sql, values) = self.build_any(&query_builder);
         inject_parameters(&sql, values, &query_builder)
     }
+}
+
+/// The row data of an [`InsertStatement`]: either literal value rows, or the
+/// result set of a `SELECT` statement inserted wholesale.
+#[derive(Clone)]
+pub(crate) enum InsertValueSource {
+    Values(Vec<Vec<InsertValue>>),
+    Select(Box<SelectStatement>),
+}
+
+/// A single value to be written for a column in an [`InsertStatement`] row:
+/// either an explicit [`Value`], or the bare SQL keyword `DEFAULT`, letting
+/// the database fall back to a column default (e.g. an auto-increment id or
+/// a `DEFAULT now()` timestamp) instead of binding a `NULL` parameter.
+#[derive(Clone, Debug)]
+pub enum InsertValue {
+    Value(Value),
+    Default,
+}
+
+impl From<Value> for InsertValue {
+    fn from(value: Value) -> Self {
+        Self::Value(value)
+    }
+}
+
+/// The `ON CONFLICT` clause of an [`InsertStatement`], used to express an upsert.
+///
+/// Construct with [`OnConflict::columns`], naming the unique or primary key columns
+/// that identify a conflicting row, then chain [`OnConflict::update_columns`] to copy
+/// values from the row that was proposed for insertion, or [`OnConflict::do_nothing`]
+/// to leave the existing row untouched. Attach the result to an [`InsertStatement`]
+/// with [`InsertStatement::on_conflict`].
+///
+/// # Examples
+///
+/// See [`InsertStatement::on_conflict`].
+#[derive(Clone, Debug)]
+pub struct OnConflict {
+    pub(crate) targets: Vec<Rc<dyn Iden>>,
+    pub(crate) action: OnConflictAction,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum OnConflictAction {
+    DoNothing,
+    Update(Vec<Rc<dyn Iden>>),
+}
+
+impl OnConflict {
+    /// Set the conflict target columns, e.g. a unique or primary key.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `columns` is empty; a conflict target needs at least one column to name.
+    ///
+    /// # Examples
+    ///
+    /// See [`InsertStatement::on_conflict`].
+    pub fn columns<C: 'static>(columns: Vec<C>) -> Result<Self, String>
+        where C: Iden {
+        Self::columns_dyn(columns.into_iter().map(|c| Rc::new(c) as Rc<dyn Iden>).collect())
+    }
+
+    /// Set the conflict target columns, variation of [`OnConflict::columns`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if `targets` is empty; a conflict target needs at least one column to name.
+    pub fn columns_dyn(targets: Vec<Rc<dyn Iden>>) -> Result<Self, String> {
+        if targets.is_empty() {
+            return Err("on conflict must target at least one column".to_owned());
+        }
+        Ok(Self {
+            targets,
+            action: OnConflictAction::DoNothing,
+        })
+    }
+
+    /// On conflict do update, copying the listed columns from the row that was
+    /// proposed for insertion (`EXCLUDED`/`VALUES()` depending on backend).
+    ///
+    /// # Errors
+    ///
+    /// Errors if `columns` is empty; an update with nothing to set is not valid SQL.
+    ///
+    /// # Examples
+    ///
+    /// See [`InsertStatement::on_conflict`].
+    pub fn update_columns<C: 'static>(&mut self, columns: Vec<C>) -> Result<&mut Self, String>
+        where C: Iden {
+        self.update_columns_dyn(columns.into_iter().map(|c| Rc::new(c) as Rc<dyn Iden>).collect())
+    }
+
+    /// On conflict do update, variation of [`OnConflict::update_columns`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if `columns` is empty; an update with nothing to set is not valid SQL.
+    pub fn update_columns_dyn(&mut self, columns: Vec<Rc<dyn Iden>>) -> Result<&mut Self, String> {
+        if columns.is_empty() {
+            return Err("on conflict do update must set at least one column".to_owned());
+        }
+        self.action = OnConflictAction::Update(columns);
+        Ok(self)
+    }
+
+    /// On conflict do nothing, leaving the existing row untouched.
+    ///
+    /// # Examples
+    ///
+    /// See [`InsertStatement::on_conflict`].
+    pub fn do_nothing(&mut self) -> &mut Self {
+        self.action = OnConflictAction::DoNothing;
+        self
+    }
 }
\ No newline at end of file
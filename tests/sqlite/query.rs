@@ -0,0 +1,133 @@
+use super::*;
+
+#[test]
+fn insert_on_conflict_update() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![
+                Glyph::Id,
+                Glyph::Aspect,
+            ])
+            .values_panic(vec![
+                3.into(),
+                3.1415.into(),
+            ])
+            .on_conflict(
+                OnConflict::columns(vec![Glyph::Id])
+                    .unwrap()
+                    .update_columns(vec![Glyph::Aspect])
+                    .unwrap()
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        "INSERT INTO `glyph` (`id`, `aspect`) VALUES (3, 3.1415) ON CONFLICT (`id`) DO UPDATE SET `aspect` = `excluded`.`aspect`"
+    );
+}
+
+#[test]
+fn insert_on_conflict_do_nothing() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Id])
+            .values_panic(vec![3.into()])
+            .on_conflict(
+                OnConflict::columns(vec![Glyph::Id])
+                    .unwrap()
+                    .do_nothing()
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        "INSERT INTO `glyph` (`id`) VALUES (3) ON CONFLICT (`id`) DO NOTHING"
+    );
+}
+
+#[test]
+fn insert_on_conflict_update_rejects_empty_columns() {
+    assert_eq!(
+        OnConflict::columns(vec![Glyph::Id])
+            .unwrap()
+            .update_columns(Vec::<Glyph>::new())
+            .unwrap_err(),
+        "on conflict do update must set at least one column"
+    );
+}
+
+#[test]
+fn insert_returning() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Aspect])
+            .values_panic(vec![3.1415.into()])
+            .returning(vec![Glyph::Id])
+            .to_string(SqliteQueryBuilder),
+        "INSERT INTO `glyph` (`aspect`) VALUES (3.1415) RETURNING `id`"
+    );
+}
+
+#[test]
+fn insert_default_values() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .default_values()
+            .to_string(SqliteQueryBuilder),
+        "INSERT INTO `glyph` DEFAULT VALUES"
+    );
+}
+
+#[test]
+fn insert_json_missing_key_uses_default() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![
+                Glyph::Aspect,
+                Glyph::Image,
+            ])
+            .json(json!({
+                "aspect": 2.1345,
+            }))
+            .to_string(SqliteQueryBuilder),
+        "INSERT INTO `glyph` (`aspect`, `image`) VALUES (2.1345, DEFAULT)"
+    );
+}
+
+#[test]
+fn insert_select_from_1() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![
+                Glyph::Aspect,
+                Glyph::Image,
+            ])
+            .select_from(
+                Query::select()
+                    .columns(vec![
+                        Glyph::Aspect,
+                        Glyph::Image,
+                    ])
+                    .from(Glyph::Table)
+                    .to_owned()
+            )
+            .unwrap()
+            .to_string(SqliteQueryBuilder),
+        "INSERT INTO `glyph` (`aspect`, `image`) SELECT `aspect`, `image` FROM `glyph`"
+    );
+}
+
+#[test]
+fn insert_select_from_rejects_values() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns(vec![Glyph::Aspect])
+            .values_panic(vec![3.1415.into()])
+            .select_from(Query::select().column(Glyph::Aspect).from(Glyph::Table).to_owned())
+            .unwrap_err(),
+        "cannot insert from select after values have already been added"
+    );
+}